@@ -2,6 +2,15 @@
 
 pub mod body;
 pub mod header;
+pub mod score;
+pub mod sealed;
+pub mod validation;
+pub mod version;
+
+pub use score::{fork_choice, ForkChoice, ScoredBlock, ScoredBlockTip};
+pub use sealed::{Sealable, SealedBlock};
+pub use validation::RlpShapeError;
+pub use version::{AnyBlock, BlockVersion, VersionedBlock, VersionedBlockDecodeError};
 
 use alloc::fmt;
 use alloy_consensus::Header;
@@ -29,7 +38,6 @@ impl<T> FullBlock for T where
 pub type BlockTx<B> = <<B as Block>::Body as BlockBody>::Transaction;
 
 /// Abstraction of block data type.
-// todo: make sealable super-trait, depends on <https://github.com/paradigmxyz/reth/issues/11449>
 // todo: make with senders extension trait, so block can be impl by block type already containing
 // senders
 pub trait Block:
@@ -61,8 +69,43 @@ pub trait Block:
     /// Returns reference to block body.
     fn body(&self) -> &Self::Body;
 
+    /// Returns references to the block's header and body at the same time.
+    fn parts(&self) -> (&Self::Header, &Self::Body) {
+        (self.header(), self.body())
+    }
+
+    /// Returns mutable references to the block's header and body at the same time.
+    ///
+    /// This unblocks builder/executor code that needs to update the header (e.g. state root,
+    /// gas used) while iterating the body, without cloning or round-tripping through
+    /// [`split`](Self::split)/[`new`](Self::new).
+    fn parts_mut(&mut self) -> (&mut Self::Header, &mut Self::Body);
+
     /// Splits the block into its header and body.
     fn split(self) -> (Self::Header, Self::Body);
+
+    /// Cheaply checks whether `buf` has the outer shape of a well-formed block, without
+    /// decoding any individual field.
+    ///
+    /// Intended as a fast, DoS-resistant gate a networking layer can apply to untrusted
+    /// payloads before paying for senders recovery or a full [`Decodable`] decode. See
+    /// [`validate_rlp_shape`](Self::validate_rlp_shape) for the specific invariant that
+    /// failed.
+    fn is_well_formed(buf: &[u8]) -> bool {
+        validation::is_well_formed(buf)
+    }
+
+    /// Checks the structural shape of a block's outer RLP, returning which invariant failed
+    /// if any.
+    ///
+    /// The default implementation verifies the outer RLP is a list of the expected arity
+    /// (header, transactions list, ommers list, and an optional post-Shanghai withdrawals
+    /// list), that the declared payload length equals the buffer length, and that the
+    /// transactions, ommers and (if present) withdrawals elements are themselves lists,
+    /// without decoding individual transactions.
+    fn validate_rlp_shape(buf: &[u8]) -> Result<(), validation::RlpShapeError> {
+        validation::validate_rlp_shape(buf)
+    }
 }
 
 impl<T> Block for alloy_consensus::Block<T>
@@ -84,6 +127,10 @@ where
         &self.body
     }
 
+    fn parts_mut(&mut self) -> (&mut Self::Header, &mut Self::Body) {
+        (&mut self.header, &mut self.body)
+    }
+
     fn split(self) -> (Self::Header, Self::Body) {
         (self.header, self.body)
     }
@@ -141,3 +188,21 @@ where
         self.header = header
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::TxEnvelope;
+
+    #[test]
+    fn parts_mut_allows_simultaneous_header_and_body_mutation() {
+        let mut block = alloy_consensus::Block::<TxEnvelope>::default();
+
+        let (header, body) = block.parts_mut();
+        header.number = 42;
+        body.ommers.push(Header::default());
+
+        assert_eq!(block.header().number, 42);
+        assert_eq!(block.body().ommers.len(), 1);
+    }
+}
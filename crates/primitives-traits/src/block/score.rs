@@ -0,0 +1,90 @@
+//! Fork-choice scoring abstraction, generic over [`Block`].
+
+use core::cmp::Ordering;
+
+use alloy_primitives::B256;
+
+use crate::Block;
+
+/// Result of comparing two candidate chain tips by their cumulative
+/// [`ScoredBlock::Score`](ScoredBlock::Score).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForkChoice {
+    /// The new block extends (or replaces) the current chain tip.
+    New,
+    /// The current chain tip should be kept.
+    Old,
+}
+
+/// Extension of [`Block`] for chains that can be compared by cumulative weight.
+///
+/// Generalizes total difficulty under PoW and the constant weighting used post-merge into a
+/// single monotonically comparable [`Score`](Self::Score), so consensus/fork-choice logic can
+/// be written once against [`Block`] instead of hard-coding difficulty semantics into every
+/// consumer.
+pub trait ScoredBlock: Block {
+    /// A cumulative, monotonically comparable score for a chain ending at this block (e.g.
+    /// total difficulty pre-merge, or a constant post-merge).
+    type Score: Ord + Copy;
+
+    /// Returns the cumulative score of the chain ending at this block, given the parent's
+    /// cumulative score.
+    fn total_score(&self, parent_score: Self::Score) -> Self::Score;
+}
+
+/// A candidate chain tip: a block's cumulative score paired with its hash.
+///
+/// Bundling the two is what lets [`fork_choice`] break score ties deterministically without
+/// every caller having to remember to pass the hash alongside the score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoredBlockTip<S> {
+    /// Cumulative score of the chain ending at this block.
+    pub score: S,
+    /// Hash of this block.
+    pub hash: B256,
+}
+
+/// Decides whether `new` should replace `current` as the canonical chain tip.
+///
+/// The fork with the greater total score wins; ties are broken deterministically by the
+/// greater block hash so that independently-operating nodes converge on the same choice
+/// regardless of arrival order.
+pub fn fork_choice<S: Ord>(new: ScoredBlockTip<S>, current: ScoredBlockTip<S>) -> ForkChoice {
+    match new.score.cmp(&current.score) {
+        Ordering::Greater => ForkChoice::New,
+        Ordering::Less => ForkChoice::Old,
+        Ordering::Equal => {
+            if new.hash > current.hash {
+                ForkChoice::New
+            } else {
+                ForkChoice::Old
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tip(score: u64, hash: u8) -> ScoredBlockTip<u64> {
+        ScoredBlockTip { score, hash: B256::with_last_byte(hash) }
+    }
+
+    #[test]
+    fn higher_score_wins() {
+        assert_eq!(fork_choice(tip(2, 0), tip(1, 0)), ForkChoice::New);
+        assert_eq!(fork_choice(tip(1, 0), tip(2, 0)), ForkChoice::Old);
+    }
+
+    #[test]
+    fn tied_score_breaks_on_greater_hash() {
+        assert_eq!(fork_choice(tip(1, 2), tip(1, 1)), ForkChoice::New);
+        assert_eq!(fork_choice(tip(1, 1), tip(1, 2)), ForkChoice::Old);
+    }
+
+    #[test]
+    fn identical_tip_keeps_current() {
+        assert_eq!(fork_choice(tip(1, 1), tip(1, 1)), ForkChoice::Old);
+    }
+}
@@ -0,0 +1,143 @@
+//! A block wrapper that caches its hash instead of recomputing it on every access.
+
+use core::ops::Deref;
+use std::sync::OnceLock;
+
+use alloy_primitives::{keccak256, B256};
+use alloy_rlp::Encodable;
+
+use crate::Block;
+
+/// Extension trait for sealing a [`Block`], i.e. pairing it with its hash.
+///
+/// Implemented for every [`Block`] so callers can go from an unsealed block to a
+/// [`SealedBlock`] without an explicit `From` impl at each call site.
+pub trait Sealable: Block + Sized {
+    /// Seals the block by hashing its header RLP, caching the result.
+    fn seal_slow(self) -> SealedBlock<Self> {
+        let hash = keccak256(alloy_rlp::encode(self.header()));
+        SealedBlock { block: self, hash: OnceLock::from(hash) }
+    }
+
+    /// Seals the block with a hash that has already been computed, trusting the caller (e.g.
+    /// a value read back from the database or received from the network) instead of
+    /// recomputing it.
+    fn seal_unchecked(self, hash: B256) -> SealedBlock<Self> {
+        SealedBlock { block: self, hash: OnceLock::from(hash) }
+    }
+}
+
+impl<B: Block> Sealable for B {}
+
+/// A [`Block`] paired with its hash, computed at most once.
+///
+/// The hash is stored in a [`OnceLock`] rather than recomputed on every call, and rather than
+/// cached behind interior mutability on the header itself: sealing is the single point where
+/// the hash is either computed or accepted from a trusted source, so reads never need a
+/// `&mut` borrow to populate the cache.
+#[derive(Debug, Clone)]
+pub struct SealedBlock<B: Block> {
+    block: B,
+    hash: OnceLock<B256>,
+}
+
+impl<B: Block> SealedBlock<B> {
+    /// Returns the block's hash. Sealing always stores the hash; it is only computed here
+    /// (and cached) on the first access after `update_block` has cleared it.
+    pub fn hash(&self) -> B256 {
+        *self.hash.get_or_init(|| keccak256(alloy_rlp::encode(self.block.header())))
+    }
+
+    /// Consumes the sealed block, discarding the cached hash.
+    pub fn into_block(self) -> B {
+        self.block
+    }
+
+    /// Returns a reference to the inner, unsealed block.
+    pub fn block(&self) -> &B {
+        &self.block
+    }
+}
+
+impl<B: Block> Deref for SealedBlock<B> {
+    type Target = B;
+
+    fn deref(&self) -> &Self::Target {
+        &self.block
+    }
+}
+
+impl<B: Block> PartialEq for SealedBlock<B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.block == other.block
+    }
+}
+
+impl<B: Block> Eq for SealedBlock<B> {}
+
+#[cfg(any(test, feature = "test-utils"))]
+mod test_utils {
+    use super::*;
+    use crate::block::TestBlock;
+
+    impl<B: TestBlock> SealedBlock<B> {
+        /// Mutates the inner block's header and body via the given closure, invalidating the
+        /// cached hash so it is recomputed from the new header on next access.
+        pub fn update_block(&mut self, f: impl FnOnce(&mut B)) {
+            f(&mut self.block);
+            self.hash = OnceLock::new();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::TestBlock;
+    use alloy_consensus::TxEnvelope;
+
+    type Block1 = alloy_consensus::Block<TxEnvelope>;
+
+    #[test]
+    fn seal_slow_computes_header_hash() {
+        let block = Block1::default();
+        let expected = keccak256(alloy_rlp::encode(block.header()));
+        let sealed = block.clone().seal_slow();
+        assert_eq!(sealed.hash(), expected);
+        assert_eq!(sealed.block(), &block);
+    }
+
+    #[test]
+    fn seal_unchecked_trusts_given_hash() {
+        let block = Block1::default();
+        let fake_hash = B256::with_last_byte(1);
+        let sealed = block.seal_unchecked(fake_hash);
+        // `seal_unchecked` must not recompute the hash even though it differs from the real one.
+        assert_eq!(sealed.hash(), fake_hash);
+    }
+
+    #[test]
+    fn hash_is_cached_after_first_access() {
+        let sealed = Block1::default().seal_slow();
+        assert_eq!(sealed.hash(), sealed.hash());
+    }
+
+    #[test]
+    fn update_block_invalidates_cached_hash() {
+        let mut sealed = Block1::default().seal_slow();
+        let original_hash = sealed.hash();
+
+        sealed.update_block(|b| b.set_block_number(42));
+        let updated_hash = sealed.hash();
+
+        assert_ne!(original_hash, updated_hash);
+        assert_eq!(updated_hash, keccak256(alloy_rlp::encode(sealed.block().header())));
+    }
+
+    #[test]
+    fn deref_exposes_inner_block() {
+        let block = Block1::default();
+        let sealed = block.clone().seal_slow();
+        assert_eq!(sealed.body(), block.body());
+    }
+}
@@ -0,0 +1,165 @@
+//! Cheap, structural pre-validation of block RLP payloads, ahead of a full decode.
+
+use alloy_rlp::{Header as RlpHeader, EMPTY_LIST_CODE};
+
+/// A structural invariant that a block's outer RLP shape failed to satisfy.
+///
+/// Distinct from [`alloy_rlp::Error`]: these are checked *before* attempting a full
+/// [`Decodable`](alloy_rlp::Decodable) decode, so they describe shape problems rather than
+/// field-level decode failures. Truncated or non-canonical input surfaces as
+/// [`RlpShapeError::Rlp`] rather than being folded into [`RlpShapeError::NotAList`], since
+/// those are decode failures rather than shape mismatches.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RlpShapeError {
+    /// The outer item was not an RLP list.
+    #[error("block payload is not an RLP list")]
+    NotAList,
+    /// The outer list did not have [`BLOCK_ARITY_MIN`]..=[`BLOCK_ARITY_MAX`] elements (header,
+    /// transactions, ommers, and an optional withdrawals list post-Shanghai).
+    #[error("expected {BLOCK_ARITY_MIN} or {BLOCK_ARITY_MAX} top-level elements, found {found}")]
+    UnexpectedArity {
+        /// Number of elements actually present.
+        found: usize,
+    },
+    /// The RLP header declared a payload length shorter than the remaining buffer, i.e. there
+    /// are trailing bytes. A buffer shorter than the declared length is reported as
+    /// [`RlpShapeError::Rlp`].
+    #[error("declared RLP length does not match buffer length")]
+    LengthMismatch,
+    /// The transactions element was not itself an RLP list.
+    #[error("transactions element is not an RLP list")]
+    TransactionsNotAList,
+    /// The ommers element was not itself an RLP list.
+    #[error("ommers element is not an RLP list")]
+    OmmersNotAList,
+    /// The (post-Shanghai) withdrawals element was present but not itself an RLP list.
+    #[error("withdrawals element is not an RLP list")]
+    WithdrawalsNotAList,
+    /// An inner RLP header failed to decode (truncated input, non-canonical length, etc.),
+    /// as opposed to decoding into an unexpected shape.
+    #[error("malformed RLP header: {0}")]
+    Rlp(#[from] alloy_rlp::Error),
+}
+
+/// Minimum number of top-level elements in the block RLP layout: header, transactions, ommers.
+const BLOCK_ARITY_MIN: usize = 3;
+
+/// Maximum number of top-level elements: the above plus a post-Shanghai withdrawals list.
+const BLOCK_ARITY_MAX: usize = 4;
+
+/// Cheaply checks whether `buf` has the outer shape of a well-formed block, without decoding
+/// any individual field.
+///
+/// This is a fast, allocation-free gate a networking layer can apply to untrusted payloads
+/// before paying for sender recovery or a full [`Decodable`](alloy_rlp::Decodable) decode,
+/// mirroring the `Block::is_good` structural check used by early Ethereum clients.
+pub fn is_well_formed(buf: &[u8]) -> bool {
+    validate_rlp_shape(buf).is_ok()
+}
+
+/// Checks the structural shape of a block's outer RLP, returning which invariant failed if
+/// any.
+///
+/// Verifies that:
+/// - the outer item is a list of [`BLOCK_ARITY_MIN`]..=[`BLOCK_ARITY_MAX`] elements (header,
+///   transactions, ommers, and an optional withdrawals list for post-Shanghai blocks),
+/// - the declared payload length matches the buffer length exactly (no trailing bytes),
+/// - the transactions, ommers, and (if present) withdrawals elements are themselves lists.
+///
+/// It does not decode the header or any individual transaction/ommer/withdrawal.
+pub fn validate_rlp_shape(buf: &[u8]) -> Result<(), RlpShapeError> {
+    let mut rest = buf;
+    let header = RlpHeader::decode(&mut rest)?;
+    if !header.list {
+        return Err(RlpShapeError::NotAList);
+    }
+    if header.payload_length != rest.len() {
+        return Err(RlpShapeError::LengthMismatch);
+    }
+
+    let mut elements = 0usize;
+    let mut transactions_list = None;
+    let mut ommers_list = None;
+    let mut withdrawals_list = None;
+    while !rest.is_empty() {
+        let is_list = rest[0] >= EMPTY_LIST_CODE;
+        let element_header = RlpHeader::decode(&mut rest)?;
+        rest = &rest[element_header.payload_length..];
+
+        elements += 1;
+        match elements {
+            2 => transactions_list = Some(is_list),
+            3 => ommers_list = Some(is_list),
+            4 => withdrawals_list = Some(is_list),
+            _ => {}
+        }
+    }
+
+    if !(BLOCK_ARITY_MIN..=BLOCK_ARITY_MAX).contains(&elements) {
+        return Err(RlpShapeError::UnexpectedArity { found: elements });
+    }
+    if transactions_list != Some(true) {
+        return Err(RlpShapeError::TransactionsNotAList);
+    }
+    if ommers_list != Some(true) {
+        return Err(RlpShapeError::OmmersNotAList);
+    }
+    if elements == BLOCK_ARITY_MAX && withdrawals_list != Some(true) {
+        return Err(RlpShapeError::WithdrawalsNotAList);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Block;
+    use alloy_consensus::{Block as AlloyBlock, TxEnvelope};
+    use alloy_eips::eip4895::Withdrawals;
+    use alloy_rlp::Encodable;
+
+    fn encode(block: &AlloyBlock<TxEnvelope>) -> Vec<u8> {
+        let mut buf = Vec::new();
+        block.encode(&mut buf);
+        buf
+    }
+
+    #[test]
+    fn accepts_pre_shanghai_block() {
+        let mut block = AlloyBlock::<TxEnvelope>::default();
+        block.body.withdrawals = None;
+        let buf = encode(&block);
+        assert!(is_well_formed(&buf));
+    }
+
+    #[test]
+    fn accepts_post_shanghai_block_with_withdrawals() {
+        let mut block = AlloyBlock::<TxEnvelope>::default();
+        block.body.withdrawals = Some(Withdrawals::new(vec![]));
+        let buf = encode(&block);
+        assert!(is_well_formed(&buf));
+    }
+
+    #[test]
+    fn rejects_truncated_input_as_rlp_error() {
+        let block = AlloyBlock::<TxEnvelope>::default();
+        let buf = encode(&block);
+        let truncated = &buf[..buf.len() - 1];
+        assert!(matches!(validate_rlp_shape(truncated), Err(RlpShapeError::Rlp(_))));
+    }
+
+    #[test]
+    fn rejects_trailing_bytes() {
+        let block = AlloyBlock::<TxEnvelope>::default();
+        let mut buf = encode(&block);
+        buf.push(0);
+        assert_eq!(validate_rlp_shape(&buf), Err(RlpShapeError::LengthMismatch));
+    }
+
+    #[test]
+    fn rejects_non_list_payload() {
+        let buf = alloy_rlp::encode(&42u64);
+        assert_eq!(validate_rlp_shape(&buf), Err(RlpShapeError::NotAList));
+    }
+}
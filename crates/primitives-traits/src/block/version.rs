@@ -0,0 +1,297 @@
+//! Support for decoding historical block layouts while only ever emitting the current one.
+
+use alloy_rlp::{BufMut, Decodable, Encodable};
+
+use crate::{Block, InMemorySize, SignedTransaction};
+
+/// Discriminant identifying the RLP layout used to encode a [`VersionedBlock`].
+///
+/// New variants are added as a chain's block layout evolves. Existing variants must never
+/// change their wire representation, so historical payloads keep decoding losslessly even
+/// after newer variants are introduced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum BlockVersion {
+    /// The original block layout: a single [`Header`](crate::BlockHeader) and
+    /// [`Body`](crate::BlockBody).
+    V1,
+}
+
+impl BlockVersion {
+    /// The most recently added [`BlockVersion`].
+    ///
+    /// [`VersionedBlock`] implementations should default to encoding this version for new
+    /// blocks, while still being able to decode older ones.
+    pub const LATEST: Self = Self::V1;
+
+    /// Returns the wire discriminant for this version.
+    pub const fn as_u8(self) -> u8 {
+        match self {
+            Self::V1 => 1,
+        }
+    }
+
+    /// Attempts to parse a wire discriminant into a known [`BlockVersion`].
+    pub const fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::V1),
+            _ => None,
+        }
+    }
+}
+
+impl Default for BlockVersion {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+/// Error returned when a block payload carries an unknown or invalid version tag.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum VersionedBlockDecodeError {
+    /// The version tag did not match any known [`BlockVersion`].
+    #[error("unknown block version: {0}")]
+    UnknownVersion(u8),
+    /// The RLP payload itself failed to decode.
+    #[error("failed to decode versioned block: {0}")]
+    Rlp(#[from] alloy_rlp::Error),
+}
+
+/// Extension of [`Block`] for types whose RLP layout varies across protocol versions.
+///
+/// A type that only ever has one layout (e.g. [`alloy_consensus::Block`] today) can implement
+/// this trait directly, with [`version`](Self::version) always returning the same
+/// [`BlockVersion`]. To actually support multiple coexisting layouts, model the block as a
+/// `#[non_exhaustive]` enum with one variant per [`BlockVersion`] — see [`AnyBlock`] — so that
+/// adding a future layout is just a new variant owning its own `Header`/`Body` associated
+/// types, never breaking exhaustive matches in downstream crates. Either way, decoding
+/// dispatches on an explicit version tag rather than sniffing the payload shape, so old
+/// payloads round-trip losslessly and mixed-version blocks can be stored or gossiped without
+/// a hard fork in the type system.
+///
+/// [`encode_versioned`](Self::encode_versioned)/[`decode`](Self::decode) are the
+/// self-describing pair: `encode_versioned` writes the tag, `decode` reads it back out of the
+/// buffer before dispatching. [`decode_versioned`](Self::decode_versioned) is the lower-level
+/// half of that pair, for callers that already know the version out of band (e.g. a caller
+/// that stores the tag in a separate column).
+pub trait VersionedBlock: Block {
+    /// Returns the version this block instance is (or would be) encoded as.
+    fn version(&self) -> BlockVersion;
+
+    /// Decodes a block of the given `version` from `buf`, advancing the slice past the
+    /// consumed bytes.
+    ///
+    /// Implementations should reject a version/payload mismatch with
+    /// [`VersionedBlockDecodeError::UnknownVersion`] rather than attempting a best-effort
+    /// decode.
+    fn decode_versioned(
+        version: BlockVersion,
+        buf: &mut &[u8],
+    ) -> Result<Self, VersionedBlockDecodeError>;
+
+    /// Encodes this block prefixed with its [`BlockVersion`] tag.
+    ///
+    /// Pairs with [`decode`](Self::decode), which reads the tag back out of the buffer, so a
+    /// single byte stream can carry blocks of differing versions without the reader needing
+    /// out-of-band knowledge of which layout was used.
+    fn encode_versioned(&self, out: &mut dyn alloy_rlp::BufMut) {
+        out.put_u8(self.version().as_u8());
+        alloy_rlp::Encodable::encode(self, out);
+    }
+
+    /// Reads a [`BlockVersion`] tag off the front of `buf`, then decodes the block that
+    /// follows, advancing `buf` past both.
+    ///
+    /// This is the self-describing counterpart to
+    /// [`encode_versioned`](Self::encode_versioned): unlike
+    /// [`decode_versioned`](Self::decode_versioned), the caller does not need to already know
+    /// the version.
+    fn decode(buf: &mut &[u8]) -> Result<Self, VersionedBlockDecodeError> {
+        let Some((&tag, rest)) = buf.split_first() else {
+            return Err(VersionedBlockDecodeError::Rlp(alloy_rlp::Error::InputTooShort));
+        };
+        let version =
+            BlockVersion::from_u8(tag).ok_or(VersionedBlockDecodeError::UnknownVersion(tag))?;
+        *buf = rest;
+        Self::decode_versioned(version, buf)
+    }
+}
+
+impl<T> VersionedBlock for alloy_consensus::Block<T>
+where
+    T: SignedTransaction,
+{
+    fn version(&self) -> BlockVersion {
+        BlockVersion::V1
+    }
+
+    fn decode_versioned(
+        version: BlockVersion,
+        buf: &mut &[u8],
+    ) -> Result<Self, VersionedBlockDecodeError> {
+        match version {
+            BlockVersion::V1 => Ok(<Self as alloy_rlp::Decodable>::decode(buf)?),
+        }
+    }
+}
+
+/// A block over every known [`BlockVersion`] layout, one variant per version.
+///
+/// Modeled after how Fuel-core reworked its `Block` type into a `#[non_exhaustive]` enum: each
+/// variant owns its own `Header`/`Body` pair, so a future layout is added as a new variant
+/// rather than a breaking change to an existing one, and `Default` always delegates to
+/// [`BlockVersion::LATEST`]. This lets downstream crates (e.g. a gossip or storage layer) hold
+/// and decode blocks of differing versions through a single type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum AnyBlock<T: SignedTransaction> {
+    /// The original block layout.
+    V1(alloy_consensus::Block<T>),
+}
+
+impl<T: SignedTransaction> Default for AnyBlock<T> {
+    fn default() -> Self {
+        Self::V1(alloy_consensus::Block::default())
+    }
+}
+
+impl<T: SignedTransaction> InMemorySize for AnyBlock<T> {
+    fn size(&self) -> usize {
+        match self {
+            Self::V1(block) => block.size(),
+        }
+    }
+}
+
+impl<T: SignedTransaction> Encodable for AnyBlock<T> {
+    fn encode(&self, out: &mut dyn BufMut) {
+        match self {
+            Self::V1(block) => block.encode(out),
+        }
+    }
+
+    fn length(&self) -> usize {
+        match self {
+            Self::V1(block) => block.length(),
+        }
+    }
+}
+
+impl<T: SignedTransaction> Decodable for AnyBlock<T> {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Ok(Self::V1(<alloy_consensus::Block<T> as Decodable>::decode(buf)?))
+    }
+}
+
+impl<T: SignedTransaction> Block for AnyBlock<T> {
+    type Header = <alloy_consensus::Block<T> as Block>::Header;
+    type Body = <alloy_consensus::Block<T> as Block>::Body;
+
+    fn new(header: Self::Header, body: Self::Body) -> Self {
+        Self::V1(alloy_consensus::Block::new(header, body))
+    }
+
+    fn header(&self) -> &Self::Header {
+        match self {
+            Self::V1(block) => block.header(),
+        }
+    }
+
+    fn body(&self) -> &Self::Body {
+        match self {
+            Self::V1(block) => block.body(),
+        }
+    }
+
+    fn parts_mut(&mut self) -> (&mut Self::Header, &mut Self::Body) {
+        match self {
+            Self::V1(block) => block.parts_mut(),
+        }
+    }
+
+    fn split(self) -> (Self::Header, Self::Body) {
+        match self {
+            Self::V1(block) => block.split(),
+        }
+    }
+}
+
+impl<T: SignedTransaction> VersionedBlock for AnyBlock<T> {
+    fn version(&self) -> BlockVersion {
+        match self {
+            Self::V1(_) => BlockVersion::V1,
+        }
+    }
+
+    fn decode_versioned(
+        version: BlockVersion,
+        buf: &mut &[u8],
+    ) -> Result<Self, VersionedBlockDecodeError> {
+        match version {
+            BlockVersion::V1 => Ok(Self::V1(<alloy_consensus::Block<T> as Decodable>::decode(buf)?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::TxEnvelope;
+
+    type TestBlock = alloy_consensus::Block<TxEnvelope>;
+
+    #[test]
+    fn round_trips_through_self_describing_decode() {
+        let block = TestBlock::default();
+        let mut buf = Vec::new();
+        VersionedBlock::encode_versioned(&block, &mut buf);
+
+        let mut slice = buf.as_slice();
+        let decoded = <TestBlock as VersionedBlock>::decode(&mut slice).unwrap();
+        assert_eq!(decoded, block);
+        assert!(slice.is_empty());
+    }
+
+    #[test]
+    fn rejects_unknown_version_tag() {
+        let mut buf: &[u8] = &[0xff];
+        let err = <TestBlock as VersionedBlock>::decode(&mut buf).unwrap_err();
+        assert_eq!(err, VersionedBlockDecodeError::UnknownVersion(0xff));
+    }
+
+    #[test]
+    fn rejects_empty_buffer() {
+        let mut buf: &[u8] = &[];
+        assert!(matches!(
+            <TestBlock as VersionedBlock>::decode(&mut buf),
+            Err(VersionedBlockDecodeError::Rlp(_))
+        ));
+    }
+
+    #[test]
+    fn any_block_default_is_latest_version() {
+        let block = AnyBlock::<TxEnvelope>::default();
+        assert_eq!(block.version(), BlockVersion::LATEST);
+    }
+
+    #[test]
+    fn any_block_round_trips_through_self_describing_decode() {
+        let block = AnyBlock::<TxEnvelope>::V1(TestBlock::default());
+        let mut buf = Vec::new();
+        VersionedBlock::encode_versioned(&block, &mut buf);
+
+        let mut slice = buf.as_slice();
+        let decoded = <AnyBlock<TxEnvelope> as VersionedBlock>::decode(&mut slice).unwrap();
+        assert_eq!(decoded, block);
+        assert!(slice.is_empty());
+    }
+
+    #[test]
+    fn any_block_exposes_header_and_body_through_block_trait() {
+        let inner = TestBlock::default();
+        let block = AnyBlock::V1(inner.clone());
+        assert_eq!(block.header(), inner.header());
+        assert_eq!(block.body(), inner.body());
+    }
+}